@@ -1,15 +1,30 @@
-use std::collections::HashMap;
+use rand::Rng;
+use std::sync::OnceLock;
 
 pub const NUM_COLS: usize = 7;
 pub const NUM_ROWS: usize = 6;
 
-type Index = (usize, usize);
-
-#[derive(Debug, Clone)]
-pub struct Game {
-    pub num_stones: [usize; NUM_COLS as usize],
-    pub state: HashMap<Index, Token>,
-    current_player: Player,
+// Bits per column in the bitboard layout. One more than NUM_ROWS so that the
+// top row of each column is always zero, acting as a sentinel: diagonal
+// shifts of BOARD_HEIGHT-1/+1 can then never leak stones into the next
+// column.
+const BOARD_HEIGHT: usize = NUM_ROWS + 1;
+const NUM_CELLS: usize = NUM_COLS * BOARD_HEIGHT;
+
+// Zobrist hash table: one random u64 per (cell, token) pair, generated once
+// and shared by every Game. Indexed [cell][0] for OWN, [cell][1] for
+// OPPONENT, so relabelling a stone (see next_player) is a single XOR swap.
+fn zobrist_table() -> &'static [[u64; 2]; NUM_CELLS] {
+    static TABLE: OnceLock<[[u64; 2]; NUM_CELLS]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut rng = rand::thread_rng();
+        let mut table = [[0u64; 2]; NUM_CELLS];
+        for cell in table.iter_mut() {
+            cell[0] = rng.gen();
+            cell[1] = rng.gen();
+        }
+        table
+    })
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -24,46 +39,89 @@ pub enum Player {
     B,
 }
 
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub num_stones: [usize; NUM_COLS as usize],
+    // Bitboards, one bit per (col, row) cell at index col * BOARD_HEIGHT + row.
+    // `own` always holds the stones of current_player, `opp` the other
+    // player's; next_player() swaps them rather than rewriting every cell.
+    own: u64,
+    opp: u64,
+    current_player: Player,
+    // Incremental Zobrist hash of the board, used by Mcts to detect
+    // transpositions (the same position reached via different move orders).
+    hash: u64,
+}
+
 impl Game {
     pub fn new() -> Game {
         Game {
             num_stones: [0; NUM_COLS],
-            state: HashMap::<Index, Token>::new(),
-            // state: [[0; NUM_ROWS]; NUM_COLS],
+            own: 0,
+            opp: 0,
             current_player: Player::A,
+            hash: 0,
         }
     }
 
+    // Zobrist hash of the current position, including whose stones are
+    // where but not whose "turn" label (A/B) is attached.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
     // Get the currently active player
     pub fn current_player(&self) -> Player {
         self.current_player
     }
 
+    // Look up which player (if any) has a stone at (col, row), for
+    // inspection/testing.
+    pub fn token_at(&self, col: usize, row: usize) -> Option<Token> {
+        let bit = 1u64 << (col * BOARD_HEIGHT + row);
+        if self.own & bit != 0 {
+            Some(Token::OWN)
+        } else if self.opp & bit != 0 {
+            Some(Token::OPPONENT)
+        } else {
+            None
+        }
+    }
+
     // Check whether the game has ended
     pub fn is_terminal(&self) -> bool {
-        self.is_win() || (self.state.len() == NUM_COLS * NUM_ROWS)
+        self.is_win() || self.num_stones.iter().sum::<usize>() == NUM_COLS * NUM_ROWS
     }
 
-    // Switch sides. Inverts the game state so that
+    // Switch sides: current player's and opponent's bitboards trade places,
+    // which is equivalent to relabelling every stone on the board. Every
+    // occupied cell's Zobrist contribution is relabelled to match.
     pub fn next_player(&mut self) {
         match self.current_player {
             Player::A => self.current_player = Player::B,
             Player::B => self.current_player = Player::A,
         }
-        for (_, s) in self.state.iter_mut() {
-            *s = match s {
-                Token::OWN => Token::OPPONENT,
-                Token::OPPONENT => Token::OWN,
-            }
+        let table = zobrist_table();
+        let mut occupied = self.own | self.opp;
+        while occupied != 0 {
+            let cell = occupied.trailing_zeros() as usize;
+            self.hash ^= table[cell][0] ^ table[cell][1];
+            occupied &= occupied - 1;
         }
+        std::mem::swap(&mut self.own, &mut self.opp);
     }
 
     // check whether current player has won
     pub fn is_win(&self) -> bool {
-        self.check_horizontal()
-            || self.check_vertical()
-            || self.check_diag_rise()
-            || self.check_diag_fall()
+        // A direction wins if, after shifting by `s` twice, some bit is still
+        // set in all four original positions: board & (board>>s) & (board>>2s)
+        // & (board>>3s), computed as two pairwise ANDs.
+        [1, BOARD_HEIGHT, BOARD_HEIGHT - 1, BOARD_HEIGHT + 1]
+            .iter()
+            .any(|&s| {
+                let m = self.own & (self.own >> s);
+                m & (m >> (2 * s)) != 0
+            })
     }
 
     pub fn play_move(&mut self, col: usize) -> Option<usize> {
@@ -72,7 +130,9 @@ impl Game {
             return None;
         }
         self.num_stones[col] += 1;
-        self.state.insert((col, row), Token::OWN);
+        let cell = col * BOARD_HEIGHT + row;
+        self.own |= 1u64 << cell;
+        self.hash ^= zobrist_table()[cell][0];
         Some(row)
     }
 
@@ -83,76 +143,10 @@ impl Game {
         self.num_stones
             .iter()
             .enumerate()
-            .filter(|(i, &num)| num < NUM_ROWS)
+            .filter(|(_, &num)| num < NUM_ROWS)
             .map(|(i, _)| i)
             .collect()
     }
-
-    fn check_horizontal(&self) -> bool {
-        for col in 0..NUM_COLS - 3 {
-            for row in 0..self.num_stones[col] {
-                if self.state.get(&(col, row)) == Some(&Token::OWN)
-                    && self.state.get(&(col + 1, row)) == Some(&Token::OWN)
-                    && self.state.get(&(col + 2, row)) == Some(&Token::OWN)
-                    && self.state.get(&(col + 3, row)) == Some(&Token::OWN)
-                {
-                    return true;
-                }
-            }
-        }
-        false
-    }
-
-    fn check_vertical(&self) -> bool {
-        for col in 0..NUM_COLS {
-            if self.num_stones[col] <= 3 {
-                continue;
-            }
-            for row in 0..self.num_stones[col] - 3 {
-                if self.state.get(&(col, row)) == Some(&Token::OWN)
-                    && self.state.get(&(col, row + 1)) == Some(&Token::OWN)
-                    && self.state.get(&(col, row + 2)) == Some(&Token::OWN)
-                    && self.state.get(&(col, row + 3)) == Some(&Token::OWN)
-                {
-                    return true;
-                }
-            }
-        }
-        false
-    }
-
-    fn check_diag_fall(&self) -> bool {
-        for col in 0..NUM_COLS - 3 {
-            if self.num_stones[col] <= 3 {
-                continue;
-            }
-            for row in 3..self.num_stones[col] {
-                if self.state.get(&(col, row)) == Some(&Token::OWN)
-                    && self.state.get(&(col + 1, row - 1)) == Some(&Token::OWN)
-                    && self.state.get(&(col + 2, row - 2)) == Some(&Token::OWN)
-                    && self.state.get(&(col + 3, row - 3)) == Some(&Token::OWN)
-                {
-                    return true;
-                }
-            }
-        }
-        false
-    }
-
-    fn check_diag_rise(&self) -> bool {
-        for col in 0..NUM_COLS - 3 {
-            for row in 0..self.num_stones[col] {
-                if self.state.get(&(col, row)) == Some(&Token::OWN)
-                    && self.state.get(&(col + 1, row + 1)) == Some(&Token::OWN)
-                    && self.state.get(&(col + 2, row + 2)) == Some(&Token::OWN)
-                    && self.state.get(&(col + 3, row + 3)) == Some(&Token::OWN)
-                {
-                    return true;
-                }
-            }
-        }
-        false
-    }
 }
 
 #[cfg(test)]
@@ -162,8 +156,8 @@ mod tests {
     fn test_play_empty() {
         let mut g = Game::new();
         assert!(!g.play_move(0).is_none());
-        assert_eq!(g.state.get(&(0, 0)), Some(&Token::OWN));
-        assert_eq!(g.current_player, Player::A);
+        assert_eq!(g.token_at(0, 0), Some(Token::OWN));
+        assert_eq!(g.current_player(), Player::A);
     }
 
     #[test]
@@ -235,9 +229,24 @@ mod tests {
             g.play_move(6);
         }
         assert_eq!(g.legal_moves(), vec![0, 1, 2, 3, 4, 5]);
-        for i in 0..4 {
+        for _ in 0..4 {
             g.play_move(5);
         }
         assert_eq!(g.legal_moves(), vec![]);
     }
+
+    #[test]
+    fn test_hash_independent_of_move_order() {
+        let mut a = Game::new();
+        for col in [2, 5, 3] {
+            a.play_move(col);
+            a.next_player();
+        }
+        let mut b = Game::new();
+        for col in [3, 5, 2] {
+            b.play_move(col);
+            b.next_player();
+        }
+        assert_eq!(a.hash(), b.hash());
+    }
 }
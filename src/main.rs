@@ -1,15 +1,18 @@
 mod game;
-mod mcts;
+mod strategy;
 
 extern crate sdl2;
 
 use sdl2::event::Event;
 use sdl2::keyboard::Keycode;
 use sdl2::pixels;
-use std::time::Duration;
 
 use sdl2::gfx::primitives::DrawRenderer;
 
+use strategy::mcts::{Mcts, MctsConfig, RolloutPolicy};
+use strategy::minimax::Minimax;
+use strategy::Strategy;
+
 const MARGIN: usize = 40;
 const SPACING: usize = 100;
 const SLOT_SIZE: usize = SPACING / 2 - 8;
@@ -17,6 +20,23 @@ const SLOT_SIZE: usize = SPACING / 2 - 8;
 const WIDTH: usize = game::NUM_COLS * SPACING + 2 * MARGIN;
 const HEIGHT: usize = game::NUM_ROWS * SPACING + 2 * MARGIN;
 
+const MINIMAX_DEPTH: usize = 6;
+// Root-parallelization fan-out for the Mcts strategy; raise this on
+// multicore machines for substantially more simulations per turn.
+const MCTS_THREADS: usize = 4;
+const MCTS_CONFIG: MctsConfig = MctsConfig {
+    exploration: 2.0,
+    rollout: RolloutPolicy::WinBlock,
+};
+
+fn new_strategy(use_minimax: bool) -> Box<dyn Strategy> {
+    if use_minimax {
+        Box::new(Minimax::new(MINIMAX_DEPTH))
+    } else {
+        Box::new(Mcts::with_threads(MCTS_THREADS).with_config(MCTS_CONFIG))
+    }
+}
+
 fn show_move(
     canvas: &mut sdl2::render::Canvas<sdl2::video::Window>,
     g: &mut game::Game,
@@ -76,11 +96,8 @@ fn main() -> Result<(), String> {
 
     let mut events = sdl_context.event_pump()?;
     let mut g = game::Game::new();
-    let mut mcts = mcts::Mcts::new();
-    // Todo: Hacky warmup to initialize first node
-    for _ in 0..20 {
-        mcts.mcts_iteration(false);
-    }
+    let mut use_minimax = false;
+    let mut strategy = new_strategy(use_minimax);
 
     'main: loop {
         for event in events.poll_iter() {
@@ -98,10 +115,16 @@ fn main() -> Result<(), String> {
                             println!("Reset");
                             g = game::Game::new();
                             reset_canvas(&mut canvas);
-                            mcts = mcts::Mcts::new();
-                            for _ in 0..20 {
-                                mcts.mcts_iteration(false);
-                            }
+                            strategy = new_strategy(use_minimax);
+                        }
+                        // Toggle which strategy plays the bot's moves
+                        Keycode::M => {
+                            use_minimax = !use_minimax;
+                            strategy = new_strategy(use_minimax);
+                            println!(
+                                "Strategy: {}",
+                                if use_minimax { "Minimax" } else { "Mcts" }
+                            );
                         }
                         _ => continue,
                     }
@@ -114,16 +137,10 @@ fn main() -> Result<(), String> {
                     x -= MARGIN;
                     let col = x / SPACING;
                     show_move(&mut canvas, &mut g, col);
-                    mcts.execute_move(col);
 
-                    for _ in 0..1000 {
-                        mcts.mcts_iteration(false);
-                    }
-                    mcts.mcts_iteration(true);
-                    let (best_move, u) = mcts.best_move();
-                    println!("Best Move: {}, Utility: {}", best_move, u);
+                    let best_move = strategy.choose_move(&g);
+                    println!("Best Move: {}", best_move);
                     show_move(&mut canvas, &mut g, best_move);
-                    mcts.execute_move(best_move);
                 }
                 _ => {}
             }
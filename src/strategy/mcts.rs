@@ -0,0 +1,723 @@
+use crate::game;
+
+use petgraph::prelude::NodeIndex;
+use petgraph::visit::EdgeRef;
+use petgraph::Graph;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Number of iterations to run between clock reads in choose_move_timed, so
+// that time-budgeted search doesn't pay for an Instant::now() every iteration.
+const TIME_CHECK_INTERVAL: usize = 64;
+
+// How rollout() picks moves during the SIMULATE stage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RolloutPolicy {
+    // Play uniformly at random among legal moves.
+    Uniform,
+    // Play an immediate winning move if one exists, else block an immediate
+    // opponent win, else fall back to Uniform. Cheap to compute and keeps
+    // playouts from meandering past decisive positions.
+    WinBlock,
+}
+
+// Tunable knobs for Mcts: the UCB exploration weight and the rollout policy.
+#[derive(Debug, Clone, Copy)]
+pub struct MctsConfig {
+    pub exploration: f32,
+    pub rollout: RolloutPolicy,
+}
+
+impl Default for MctsConfig {
+    fn default() -> Self {
+        MctsConfig {
+            exploration: 2.0,
+            rollout: RolloutPolicy::Uniform,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    value: f32,
+    visits: f32,
+    state: game::Game,
+}
+
+impl Node {
+    fn new(g: game::Game) -> Node {
+        Node {
+            value: 0.0,
+            visits: 0.0,
+            state: g,
+        }
+    }
+
+    fn ucb(&self, parent: &Node, exploration: f32) -> f32 {
+        if self.visits == 0.0 {
+            return f32::MAX;
+        }
+        // With transposition merging, a child can already carry visits from
+        // elsewhere in the graph even the first time its parent is expanded
+        // (parent.visits == 0.0). ln(0) is -inf there, so fall back to pure
+        // exploitation instead of feeding NaN into the comparison below.
+        if parent.visits == 0.0 {
+            return self.value / self.visits;
+        }
+        let mut ans = parent.visits.ln();
+        ans /= self.visits;
+        exploration * ans.sqrt() + self.value / self.visits
+    }
+
+    fn utility(&self) -> f32 {
+        self.value / self.visits
+    }
+}
+
+#[derive(Debug)]
+pub struct Mcts {
+    graph: Graph<Node, usize, petgraph::Directed>,
+    root: NodeIndex,
+    last_simulation_count: usize,
+    // Maps a position's Zobrist hash to the node that already represents it,
+    // so expand() can merge transpositions (the same board reached via
+    // different move orders) instead of fragmenting their statistics across
+    // duplicate subtrees.
+    transposition: HashMap<u64, NodeIndex>,
+    // Number of workers search_parallel splits into when driven through
+    // Strategy::choose_move. 1 means "just search on this thread".
+    threads: usize,
+    config: MctsConfig,
+    // The game state Strategy::choose_move last saw, in the caller's own
+    // play_move-then-next_player convention. Compared against the next
+    // incoming state to figure out which move the opponent played, so the
+    // tree can be advanced with execute_move instead of rebuilt from
+    // scratch.
+    tracked_state: game::Game,
+}
+
+impl Mcts {
+    pub fn new() -> Mcts {
+        Mcts::new_with_state(game::Game::new())
+    }
+
+    // Root the search tree at an arbitrary game state, rather than a fresh
+    // board. Used directly, and as the fallback sync_to falls back to
+    // whenever the incoming game state can't be explained as a single move
+    // on top of the existing tree.
+    pub fn new_with_state(state: game::Game) -> Mcts {
+        let mut g = Graph::<Node, usize, petgraph::Directed>::new();
+        let hash = state.hash();
+        let tracked_state = state.clone();
+        let r = g.add_node(Node::new(state));
+        let mut transposition = HashMap::new();
+        transposition.insert(hash, r);
+        Mcts {
+            graph: g,
+            root: r,
+            last_simulation_count: 0,
+            transposition,
+            threads: 1,
+            config: MctsConfig::default(),
+            tracked_state,
+        }
+    }
+
+    // Same as new(), but when `threads` > 1, search_parallel is used with
+    // `threads` workers whenever this Mcts is driven through
+    // Strategy::choose_move (otherwise the single-threaded timed search is).
+    pub fn with_threads(threads: usize) -> Mcts {
+        let mut m = Mcts::new();
+        m.threads = threads;
+        m
+    }
+
+    // Override the UCB exploration weight and rollout policy.
+    pub fn with_config(mut self, config: MctsConfig) -> Mcts {
+        self.config = config;
+        self
+    }
+
+    // Select child with highest UCB. If several, pick one at random
+    fn select_next_child(&self, current_node: NodeIndex) -> Option<NodeIndex> {
+        let nodes_ucb =
+            |c: NodeIndex, p: NodeIndex| self.graph[c].ucb(&self.graph[p], self.config.exploration);
+        // Get all child nodes
+        let children: Vec<_> = self
+            .graph
+            .neighbors_directed(current_node, petgraph::Outgoing)
+            .map(|c| (nodes_ucb(c, current_node), c))
+            .collect();
+        // Node is not expanded
+        if children.len() == 0 {
+            return None;
+        }
+        // Pack children into tuples with their respective UCB
+        let max_ucb = children
+            .iter()
+            .map(|(ucb, _)| ucb)
+            .max_by(|a, b| a.partial_cmp(b).unwrap())
+            .unwrap();
+        // Narrow selection to children with highest UCB
+        let choice: Vec<_> = children
+            .iter()
+            .filter(|(ucb, _)| ucb == max_ucb)
+            .map(|(_, c)| *c)
+            .collect();
+        // If highest UCB is shared, pick one child at random
+        if choice.len() > 1 {
+            let mut rng = thread_rng();
+            let c = choice.choose(&mut rng).unwrap();
+            return Some(*c);
+        }
+        Some(choice[0])
+    }
+
+    // SELECT stage of algorithm: Pick path to most promising leaf node
+    fn select(&self) -> Vec<NodeIndex> {
+        let mut current_node = self.root;
+        let mut path = vec![current_node];
+        while let Some(next_node) = self.select_next_child(current_node) {
+            path.push(next_node);
+            current_node = next_node;
+        }
+        path
+    }
+
+    // EXPAND stage: Create children for leaf node, pick one at random
+    // and run rollout
+    fn expand(&mut self, node: NodeIndex) -> bool {
+        // let current_state = &self.graph[node].state;
+        let moves = self.graph[node].state.legal_moves();
+        if moves.len() == 0 {
+            return false;
+        }
+
+        for m in moves {
+            // FIXME: This allocates for every child at creation
+            // Allocate during evalution instead to make it
+            // faster
+            let mut new_state = self.graph[node].state.clone();
+            new_state.next_player();
+            new_state.play_move(m);
+            let hash = new_state.hash();
+            // Reuse the existing node if this move transposes into a
+            // position already reached elsewhere in the graph, instead of
+            // creating a duplicate with its own separate statistics.
+            let child = match self.transposition.get(&hash) {
+                Some(&existing) => existing,
+                None => {
+                    let idx = self.graph.add_node(Node::new(new_state));
+                    self.transposition.insert(hash, idx);
+                    idx
+                }
+            };
+            self.graph.add_edge(node, child, m);
+        }
+        true
+    }
+
+    // SIMULATE stage: From leaf node, run a simulation, picking moves
+    // according to self.config.rollout
+    fn rollout(&mut self, node: NodeIndex) -> Option<game::Player> {
+        let mut g = self.graph[node].state.clone();
+        let mut rng = thread_rng();
+        // Check if game has ended
+        while !g.is_terminal() {
+            g.next_player();
+            if let Some(m) = self.rollout_move(&g, &mut rng) {
+                g.play_move(m);
+            } else {
+                println!("No legal moves {:?}", g.num_stones);
+            }
+        }
+        // Win/Loss
+        if g.is_win() {
+            return Some(g.current_player());
+        }
+        // Draw
+        None
+    }
+
+    // Pick the next move during a rollout. Under WinBlock, prefer an
+    // immediate win, then an immediate block of the opponent's next-turn
+    // win, falling back to Uniform in both other cases.
+    fn rollout_move(&self, g: &game::Game, rng: &mut impl Rng) -> Option<usize> {
+        let moves = g.legal_moves();
+        if self.config.rollout == RolloutPolicy::WinBlock {
+            if let Some(&m) = moves.iter().find(|&&m| {
+                let mut wins = g.clone();
+                wins.play_move(m);
+                wins.is_win()
+            }) {
+                return Some(m);
+            }
+            if let Some(&m) = moves.iter().find(|&&m| {
+                let mut blocks = g.clone();
+                blocks.next_player();
+                blocks.play_move(m);
+                blocks.is_win()
+            }) {
+                return Some(m);
+            }
+        }
+        moves.choose(rng).copied()
+    }
+
+    // BACKPROP stage: Update nodes along path with simulation results
+    fn backprop(&mut self, path: Vec<NodeIndex>, mut value: f32) {
+        for n in path {
+            self.graph[n].value += value;
+            self.graph[n].visits += 1.0;
+            value *= -1.0;
+        }
+    }
+
+    // Helper tool to pretty-print a path through the graph
+    fn pprint_path(&self, path: &Vec<NodeIndex>, info: &str) {
+        let node_pprint = |node_idx: &NodeIndex| -> String {
+            let n = &self.graph[*node_idx];
+            format!(
+                "idx: {} value: {} visits: {} u: {}",
+                node_idx.index(),
+                n.value,
+                n.visits,
+                n.value / n.visits
+            )
+        };
+        let s = path
+            .iter()
+            .map(|n| node_pprint(n))
+            .collect::<Vec<String>>()
+            .join("\n   --- ");
+        println!("{} {}", info, s);
+    }
+
+    // One full MCTS iteration
+    pub fn mcts_iteration(&mut self, verbose: bool) {
+        let mut path = self.select();
+        let current_node = *path.last().unwrap();
+
+        if verbose {
+            self.pprint_path(
+                &self.graph.node_indices().collect::<Vec<NodeIndex>>(),
+                "All Nodes",
+            );
+            self.pprint_path(&path, "Path before expansion");
+        }
+
+        if self.expand(current_node) {
+            let next_node = self.select_next_child(current_node).unwrap();
+            path.push(next_node);
+        }
+
+        let mut value = 0.0;
+        if let Some(winner) = self.rollout(*path.last().unwrap()) {
+            if winner == self.graph[self.root].state.current_player() {
+                value = 1.0;
+            } else {
+                value = -1.0;
+            }
+        }
+        self.backprop(path.clone(), value);
+        if verbose {
+            println!("Playout Result: {}", value);
+            self.pprint_path(&path, "Path after backprop");
+        }
+    }
+
+    // Run MCTS iterations until max_time has elapsed, then return the best
+    // move found so far. This is an anytime search: it always has a usable
+    // answer, it just gets stronger the longer max_time is. Elapsed time is
+    // only checked every TIME_CHECK_INTERVAL iterations to amortize the cost
+    // of reading the clock.
+    pub fn choose_move_timed(&mut self, max_time: Duration) -> (usize, f32) {
+        let start = Instant::now();
+        let mut simulations = 0;
+        loop {
+            for _ in 0..TIME_CHECK_INTERVAL {
+                self.mcts_iteration(false);
+                simulations += 1;
+            }
+            if start.elapsed() >= max_time {
+                break;
+            }
+        }
+        self.last_simulation_count = simulations;
+        self.best_move()
+    }
+
+    // Number of simulations performed by the most recent choose_move_timed
+    // call, exposed for debugging.
+    pub fn simulations_run(&self) -> usize {
+        self.last_simulation_count
+    }
+
+    // Pick best move from MCTS graph
+    pub fn best_move(&mut self) -> (usize, f32) {
+        let best_child = self
+            .graph
+            .neighbors_directed(self.root, petgraph::Outgoing)
+            .max_by(|n1, n2| {
+                self.graph[*n1]
+                    .utility()
+                    .partial_cmp(&self.graph[*n2].utility())
+                    .unwrap()
+            })
+            .unwrap();
+        let best_move = self.graph[self.graph.find_edge(self.root, best_child).unwrap()];
+        (best_move, self.graph[best_child].utility())
+    }
+
+    // Update internal state to reflect a move in the game
+    pub fn execute_move(&mut self, m: usize) {
+        // The root may not have been expanded yet (e.g. it was just reached
+        // by a previous execute_move and never searched), in which case
+        // there's no edge for `m` to find.
+        if self
+            .graph
+            .neighbors_directed(self.root, petgraph::Outgoing)
+            .next()
+            .is_none()
+        {
+            self.expand(self.root);
+        }
+
+        let outgoing_edge = self
+            .graph
+            .edges_directed(self.root, petgraph::Outgoing)
+            .find(|e| *e.weight() == m)
+            .unwrap();
+        let new_root_hash = self.graph[outgoing_edge.target()].state.hash();
+
+        // Collect every node still reachable from the new root, then drop
+        // the rest (dead siblings and ancestors) so a long game doesn't
+        // accumulate unbounded garbage in the graph.
+        let mut live = HashSet::new();
+        let mut stack = vec![outgoing_edge.target()];
+        while let Some(n) = stack.pop() {
+            if live.insert(n) {
+                stack.extend(self.graph.neighbors_directed(n, petgraph::Outgoing));
+            }
+        }
+        self.graph.retain_nodes(|_, n| live.contains(&n));
+
+        // retain_nodes can renumber surviving NodeIndexes, so relocate the
+        // root by its Zobrist hash rather than trusting the old index.
+        self.root = self
+            .graph
+            .node_indices()
+            .find(|&n| self.graph[n].state.hash() == new_root_hash)
+            .expect("new root must survive pruning");
+        self.transposition = self
+            .graph
+            .node_indices()
+            .map(|n| (self.graph[n].state.hash(), n))
+            .collect();
+    }
+
+    // Which legal move out of `from` leads to `to`, if `to` is exactly one
+    // ply ahead of `from`. Both states are compared using the caller's own
+    // play_move-then-next_player convention (see Strategy::choose_move),
+    // which is independent of whichever convention the search tree's own
+    // nodes happen to use internally.
+    fn move_from(from: &game::Game, to: &game::Game) -> Option<usize> {
+        from.legal_moves().into_iter().find(|&m| {
+            let mut next = from.clone();
+            next.play_move(m);
+            next.next_player();
+            next.hash() == to.hash()
+        })
+    }
+
+    // Bring the search tree in line with the real game's current state
+    // before searching from it. If `game` is exactly the move that's just
+    // been played on top of tracked_state, advance the existing tree with
+    // execute_move so its statistics survive across turns. Otherwise (a new
+    // game, a reset, or a jump we can't explain as a single move) rebuild
+    // fresh, the same way every call used to.
+    fn sync_to(&mut self, game: &game::Game) {
+        if game.hash() == self.tracked_state.hash() {
+            return;
+        }
+        match Mcts::move_from(&self.tracked_state, game) {
+            Some(m) => self.execute_move(m),
+            None => {
+                let threads = self.threads;
+                let config = self.config;
+                // Tree nodes name the player who moved into them (see
+                // expand), while `game` names the player to move, so flip
+                // it before rooting the new tree there.
+                let mut root = game.clone();
+                root.next_player();
+                *self = Mcts::new_with_state(root).with_config(config);
+                self.threads = threads;
+            }
+        }
+        self.tracked_state = game.clone();
+    }
+
+    // Root-parallel search: `threads` independent searchers each build their
+    // own tree from the current root state, then their root-children
+    // statistics are merged into this tree by summing value and visits per
+    // move. Workers only need the root Game and hand back a small per-move
+    // summary, so there's no shared mutable graph and no lock contention.
+    // `threads` is floored at 1, so search_parallel(n, 0) still runs one
+    // worker instead of silently searching nothing.
+    pub fn search_parallel(&mut self, iterations: usize, threads: usize) {
+        let root_state = self.graph[self.root].state.clone();
+        let config = self.config;
+        let threads = threads.max(1);
+        let iterations_per_thread = (iterations / threads).max(1);
+
+        let partials: Vec<HashMap<usize, (f32, f32)>> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|_| {
+                    let state = root_state.clone();
+                    scope.spawn(move || {
+                        let mut worker = Mcts::new_with_state(state).with_config(config);
+                        for _ in 0..iterations_per_thread {
+                            worker.mcts_iteration(false);
+                        }
+                        worker.root_child_stats()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        if self
+            .graph
+            .neighbors_directed(self.root, petgraph::Outgoing)
+            .next()
+            .is_none()
+        {
+            self.expand(self.root);
+        }
+
+        for partial in partials {
+            for (m, (value, visits)) in partial {
+                let child = self
+                    .graph
+                    .edges_directed(self.root, petgraph::Outgoing)
+                    .find(|e| *e.weight() == m)
+                    .map(|e| e.target());
+                if let Some(child) = child {
+                    self.graph[child].value += value;
+                    self.graph[child].visits += visits;
+                }
+            }
+        }
+    }
+
+    // Per-move (value, visits) of the root's children, used to report a
+    // worker's search results back to search_parallel.
+    fn root_child_stats(&self) -> HashMap<usize, (f32, f32)> {
+        self.graph
+            .edges_directed(self.root, petgraph::Outgoing)
+            .map(|e| {
+                let child = &self.graph[e.target()];
+                (*e.weight(), (child.value, child.visits))
+            })
+            .collect()
+    }
+}
+
+// Search budget used when Mcts is driven through the Strategy trait, which
+// only gets a game state and must return a move with no further tuning.
+const STRATEGY_TIME_BUDGET: Duration = Duration::from_millis(1000);
+// Iterations each worker runs on the parallel path through
+// Strategy::choose_move, so the total grows with the thread count; there is
+// no clock-based cutoff inside search_parallel itself, so this plays the
+// role STRATEGY_TIME_BUDGET plays for the single-threaded search.
+const STRATEGY_PARALLEL_ITERATIONS: usize = 4000;
+
+impl super::Strategy for Mcts {
+    fn choose_move(&mut self, game: &game::Game) -> usize {
+        self.sync_to(game);
+
+        let best_move = if self.threads > 1 {
+            self.search_parallel(STRATEGY_PARALLEL_ITERATIONS * self.threads, self.threads);
+            self.best_move().0
+        } else {
+            let (best_move, _) = self.choose_move_timed(STRATEGY_TIME_BUDGET);
+            best_move
+        };
+
+        // Advance the tree for the move we're about to make ourselves, so
+        // next call's sync_to only has to explain the opponent's reply.
+        self.execute_move(best_move);
+        self.tracked_state.play_move(best_move);
+        self.tracked_state.next_player();
+        best_move
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_ucb() {
+        let mut p = Node::new(game::Game::new());
+        let mut n = Node::new(game::Game::new());
+        assert_eq!(n.ucb(&p, 2.0), f32::MAX);
+        p.visits = 2.0;
+        n.value = 20.0;
+        n.visits = 1.0;
+        let diff = (n.ucb(&p, 2.0) - 21.67).abs();
+        assert!(diff < 0.01);
+    }
+
+    #[test]
+    fn choose_move_timed_returns_legal_move_and_counts_simulations() {
+        let mut mcts = Mcts::new();
+        let (best_move, _) = mcts.choose_move_timed(Duration::from_millis(50));
+        assert!(game::Game::new().legal_moves().contains(&best_move));
+        // The clock is only checked every TIME_CHECK_INTERVAL iterations, so
+        // at least one full batch must have run, and simulations_run() is a
+        // multiple of that batch size.
+        assert!(mcts.simulations_run() > 0);
+        assert_eq!(mcts.simulations_run() % TIME_CHECK_INTERVAL, 0);
+    }
+
+    // Follow `moves` down the tree from the root, expanding nodes as
+    // needed, and return the node reached.
+    fn walk(mcts: &mut Mcts, moves: &[usize]) -> NodeIndex {
+        let mut node = mcts.root;
+        for &m in moves {
+            if mcts
+                .graph
+                .edges_directed(node, petgraph::Outgoing)
+                .next()
+                .is_none()
+            {
+                mcts.expand(node);
+            }
+            node = mcts
+                .graph
+                .edges_directed(node, petgraph::Outgoing)
+                .find(|e| *e.weight() == m)
+                .unwrap()
+                .target();
+        }
+        node
+    }
+
+    #[test]
+    fn rollout_move_takes_immediate_win_under_win_block() {
+        let mut g = game::Game::new();
+        g.play_move(0);
+        g.play_move(0);
+        g.play_move(1);
+        g.play_move(1);
+        g.play_move(2);
+        g.play_move(2);
+        // Current player has stones at columns 0, 1, 2 on row 0; column 3
+        // completes a horizontal win.
+        let mcts = Mcts::new().with_config(MctsConfig {
+            exploration: 2.0,
+            rollout: RolloutPolicy::WinBlock,
+        });
+        let mut rng = thread_rng();
+        assert_eq!(mcts.rollout_move(&g, &mut rng), Some(3));
+    }
+
+    #[test]
+    fn rollout_move_blocks_immediate_loss_under_win_block() {
+        let mut g = game::Game::new();
+        g.play_move(0);
+        g.play_move(0);
+        g.play_move(1);
+        g.play_move(1);
+        g.play_move(2);
+        g.play_move(2);
+        g.next_player();
+        // Current player's opponent threatens to win at column 3 next turn;
+        // the current player has no win of their own and must block there.
+        let mcts = Mcts::new().with_config(MctsConfig {
+            exploration: 2.0,
+            rollout: RolloutPolicy::WinBlock,
+        });
+        let mut rng = thread_rng();
+        assert_eq!(mcts.rollout_move(&g, &mut rng), Some(3));
+    }
+
+    #[test]
+    fn search_parallel_zero_threads_does_not_panic() {
+        let mut mcts = Mcts::new();
+        mcts.search_parallel(40, 0);
+        // Should behave like a single worker rather than leaving every
+        // child at 0 visits (which would make best_move's utility NaN).
+        let (_, utility) = mcts.best_move();
+        assert!(!utility.is_nan());
+    }
+
+    #[test]
+    fn search_parallel_merges_worker_stats_by_summing() {
+        let mut mcts = Mcts::new();
+        mcts.search_parallel(40, 2);
+        let total_visits: f32 = mcts
+            .graph
+            .neighbors_directed(mcts.root, petgraph::Outgoing)
+            .map(|c| mcts.graph[c].visits)
+            .sum();
+        // Each of the 2 workers runs 20 iterations, each of which adds
+        // exactly one visit to one of the root's children; if the merge
+        // overwrote instead of summing, this would be 20 instead of 40.
+        assert_eq!(total_visits, 40.0);
+    }
+
+    #[test]
+    fn expand_merges_transposed_children() {
+        let mut mcts = Mcts::new();
+        // Columns 2 and 3 are both played by the same side here (the middle
+        // move, column 5, belongs to the other side), so reordering them
+        // reaches the same position and expand should merge onto one node.
+        let via_2_5_3 = walk(&mut mcts, &[2, 5, 3]);
+        let via_3_5_2 = walk(&mut mcts, &[3, 5, 2]);
+        assert_eq!(via_2_5_3, via_3_5_2);
+    }
+
+    #[test]
+    fn move_from_detects_single_ply_continuation() {
+        let g0 = game::Game::new();
+        let mut g1 = g0.clone();
+        g1.play_move(3);
+        g1.next_player();
+        assert_eq!(Mcts::move_from(&g0, &g1), Some(3));
+    }
+
+    #[test]
+    fn move_from_returns_none_for_unrelated_state() {
+        let g0 = game::Game::new();
+        let mut unrelated = game::Game::new();
+        unrelated.play_move(0);
+        unrelated.next_player();
+        unrelated.play_move(1);
+        unrelated.next_player();
+        assert_eq!(Mcts::move_from(&g0, &unrelated), None);
+    }
+
+    #[test]
+    fn sync_to_reuses_existing_subtree_instead_of_rebuilding() {
+        let mut mcts = Mcts::new();
+        for _ in 0..200 {
+            mcts.mcts_iteration(false);
+        }
+        let nodes_before = mcts.graph.node_count();
+
+        let mut next = mcts.tracked_state.clone();
+        next.play_move(0);
+        next.next_player();
+        mcts.sync_to(&next);
+
+        // A rebuild (the old behavior) always starts from a single node;
+        // reuse keeps the surviving subtree of whatever was already there.
+        assert!(mcts.graph.node_count() > 1);
+        assert!(mcts.graph.node_count() <= nodes_before);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::game;
+use crate::game::Token;
+use crate::strategy::Strategy;
+
+// Forward-only direction vectors (col step, row step) covering all four
+// winning-line orientations; scanning every cell with these suffices to
+// visit every 4-in-a-row window exactly once.
+const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+
+const CENTER_COL: usize = game::NUM_COLS / 2;
+const CENTER_WEIGHT: f32 = 0.5;
+
+// Depth-limited negamax with alpha-beta pruning. Deterministic and strong at
+// short horizons, in contrast to the stochastic Mcts.
+pub struct Minimax {
+    max_depth: usize,
+}
+
+impl Minimax {
+    pub fn new(max_depth: usize) -> Minimax {
+        Minimax { max_depth }
+    }
+
+    // Heuristic eval for non-terminal leaves, from the perspective of
+    // whoever made the most recent move into `game` (see negamax below):
+    // count of open three-in-a-rows for that player minus the opponent's,
+    // plus a bonus for stones in the center column, which participate in
+    // more winning lines than the edges.
+    fn evaluate(game: &game::Game) -> f32 {
+        let mut own_threes = 0;
+        let mut opp_threes = 0;
+        for col in 0..game::NUM_COLS {
+            for row in 0..game::NUM_ROWS {
+                for &(dc, dr) in &DIRECTIONS {
+                    let window = Self::window(col, row, dc, dr);
+                    let window = match window {
+                        Some(w) => w,
+                        None => continue,
+                    };
+                    let own = window
+                        .iter()
+                        .filter(|&&(c, r)| game.token_at(c, r) == Some(Token::OWN))
+                        .count();
+                    let opp = window
+                        .iter()
+                        .filter(|&&(c, r)| game.token_at(c, r) == Some(Token::OPPONENT))
+                        .count();
+                    if own == 3 && opp == 0 {
+                        own_threes += 1;
+                    } else if opp == 3 && own == 0 {
+                        opp_threes += 1;
+                    }
+                }
+            }
+        }
+
+        let center_own = (0..game::NUM_ROWS)
+            .filter(|&row| game.token_at(CENTER_COL, row) == Some(Token::OWN))
+            .count();
+        let center_opp = (0..game::NUM_ROWS)
+            .filter(|&row| game.token_at(CENTER_COL, row) == Some(Token::OPPONENT))
+            .count();
+
+        (own_threes as f32 - opp_threes as f32)
+            + CENTER_WEIGHT * (center_own as f32 - center_opp as f32)
+    }
+
+    // The four (col, row) cells starting at (col, row) and stepping by
+    // (dcol, drow), or None if any of them falls off the board.
+    fn window(col: usize, row: usize, dcol: isize, drow: isize) -> Option<[(usize, usize); 4]> {
+        let mut cells = [(0usize, 0usize); 4];
+        for (k, cell) in cells.iter_mut().enumerate() {
+            let c = col as isize + dcol * k as isize;
+            let r = row as isize + drow * k as isize;
+            if c < 0 || r < 0 || c as usize >= game::NUM_COLS || r as usize >= game::NUM_ROWS {
+                return None;
+            }
+            *cell = (c as usize, r as usize);
+        }
+        Some(cells)
+    }
+
+    // Negamax over the state reached by each legal move. Mirrors Mcts::expand
+    // (next_player then play_move), so `game.current_player()` always names
+    // whoever made the most recent move into `game`; the return value is a
+    // score relative to that player, and flips sign as it unwinds.
+    // `alpha`/`beta` are likewise relative to that player.
+    fn negamax(&self, game: &game::Game, depth: usize, alpha: f32, beta: f32) -> f32 {
+        if game.is_win() {
+            return f32::INFINITY;
+        }
+        if game.is_terminal() {
+            return 0.0;
+        }
+        if depth == 0 {
+            return Self::evaluate(game);
+        }
+
+        // The opponent replies next: search their moves with the window in
+        // their perspective, take their best, and negate it back.
+        let (mut opp_alpha, opp_beta) = (-beta, -alpha);
+        let mut best = f32::NEG_INFINITY;
+        for m in game.legal_moves() {
+            let mut child = game.clone();
+            child.next_player();
+            child.play_move(m);
+            let score = self.negamax(&child, depth - 1, opp_alpha, opp_beta);
+            best = best.max(score);
+            opp_alpha = opp_alpha.max(best);
+            if opp_alpha >= opp_beta {
+                break;
+            }
+        }
+        -best
+    }
+}
+
+impl Strategy for Minimax {
+    fn choose_move(&mut self, game: &game::Game) -> usize {
+        let mut best_move = None;
+        let mut best_score = f32::NEG_INFINITY;
+        let (mut alpha, beta) = (f32::NEG_INFINITY, f32::INFINITY);
+
+        // `game.current_player()` is the player to move, so play_move alone
+        // places their stone and leaves them as the child's most recent mover.
+        for m in game.legal_moves() {
+            let mut child = game.clone();
+            child.play_move(m);
+            let score = self.negamax(&child, self.max_depth.saturating_sub(1), alpha, beta);
+            if best_move.is_none() || score > best_score {
+                best_score = score;
+                best_move = Some(m);
+            }
+            alpha = alpha.max(best_score);
+        }
+
+        best_move.expect("choose_move called on a terminal game state")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_immediate_win() {
+        let mut g = game::Game::new();
+        g.play_move(0);
+        g.play_move(0);
+        g.play_move(1);
+        g.play_move(1);
+        g.play_move(2);
+        g.play_move(2);
+        // Current player (A) has stones in columns 0, 1, 2 on rows 0 and 1;
+        // playing column 3 completes a horizontal four-in-a-row on row 0.
+        assert_eq!(Minimax::new(1).choose_move(&g), 3);
+    }
+
+    #[test]
+    fn blocks_immediate_loss() {
+        let mut g = game::Game::new();
+        g.play_move(0);
+        g.play_move(0);
+        g.play_move(1);
+        g.play_move(1);
+        g.play_move(2);
+        g.play_move(2);
+        g.next_player();
+        // It's now the opponent's turn to move, and whoever played 0, 1, 2
+        // on rows 0 and 1 threatens to win at column 3 next turn; the current
+        // player must block there.
+        assert_eq!(Minimax::new(1).choose_move(&g), 3);
+    }
+}
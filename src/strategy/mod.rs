@@ -0,0 +1,11 @@
+use crate::game;
+
+pub mod mcts;
+pub mod minimax;
+
+// Common interface for move-choosing algorithms, so callers (e.g. main) can
+// swap between MCTS, Minimax, or future strategies without caring which one
+// is in play.
+pub trait Strategy {
+    fn choose_move(&mut self, game: &game::Game) -> usize;
+}